@@ -36,6 +36,60 @@ fn main() {
     };
 
     println!("1 new tweet: {}", tweet.summarize());
+
+    // lifetimes in action
+    let string1 = String::from("long string is long");
+    let string2 = String::from("xyz");
+    println!(
+        "The longest string is {}",
+        longest(string1.as_str(), string2.as_str())
+    );
+
+    let novel = String::from("Call me Ishmael. Some years ago...");
+    let first_sentence = novel.split('.').next().expect("Could not find a '.'");
+    let excerpt = ImportantExcerpt {
+        part: first_sentence,
+    };
+    println!(
+        "Excerpt part: {}",
+        excerpt.announce_and_return_part("Here's an excerpt")
+    );
+
+    // a mix of Tweet and NewsArticle can live in one Vec because Aggregator stores trait objects
+    let article = NewsArticle {
+        headline: String::from("Penguins win the Stanley Cup Championship!"),
+        location: String::from("Pittsburgh, PA, USA"),
+        author: String::from("Iceburgh"),
+        content: String::from("The Pittsburgh Penguins once again are the best hockey team in the NHL."),
+    };
+    // the blanket Headline impl below gives every Summary type a headline() method for free
+    println!("{}", tweet.headline());
+    println!("{}", article.headline());
+
+    let mut aggregator = Aggregator::new();
+    aggregator.push(Box::new(tweet));
+    aggregator.push(Box::new(article));
+    aggregator.display_all();
+
+    // String isn't Copy, so largest_any can't take this slice, but largest_ref can
+    let string_list = vec![
+        String::from("apple"),
+        String::from("watermelon"),
+        String::from("fig"),
+    ];
+    println!("Largest string in list is {}", largest_ref(&string_list));
+
+    // distance_from_origin is implemented once for any T: Float, so both widths work
+    let float32_point = Point { x: 3.0f32, y: 4.0f32 };
+    let float64_point = Point { x: 3.0f64, y: 4.0f64 };
+    println!(
+        "Distance from origin (f32): {}",
+        float32_point.distance_from_origin()
+    );
+    println!(
+        "Distance from origin (f64): {}",
+        float64_point.distance_from_origin()
+    );
 }
 
 // to eliminate code duplication we create an abstraction by defining a function that operates on any list of integers given to it in a parameter
@@ -81,6 +135,24 @@ fn largest_any<T: PartialOrd + Copy>(list: &[T]) -> T {
     largest
 }
 
+// largest_any above requires Copy, so it can't be called on a slice of String or any other
+// heap-allocated, non-Copy type (copying list[0] into largest would move it out of the slice)
+// largest_ref sidesteps this by tracking a reference into the slice instead of an owned value,
+// so it never needs to copy anything and works for any type that is merely comparable
+// use largest_any for small Copy types (it returns by value, which is usually cheaper to work with)
+// and use largest_ref whenever T isn't Copy (or copying it would be wasteful)
+fn largest_ref<T: PartialOrd>(list: &[T]) -> &T {
+    let mut largest = &list[0];
+
+    for item in list {
+        if item > largest {
+            largest = item;
+        }
+    }
+
+    largest
+}
+
 // we can use generics to create definitions for items like function signatures or structs
 // generics are placed in the signature of the function where normally data types of the parameter and return value go
 
@@ -119,9 +191,46 @@ impl<T, U> Point<T, U> {
 }
 
 // implementing a function on certain instances of a struct
-impl Point<f32, f32> {
-    fn distance_from_origin(&self) -> f32 {
-        (self.x.powi(2) + self.y.powi(2)).sqrt()
+// rather than hard-coding this to Point<f32, f32> (which leaves Point<f64, f64> with nothing),
+// we bound it on a small local trait that captures the handful of float operations we need,
+// then implement that trait for both f32 and f64 so one method body serves both widths
+trait Float {
+    fn powi2(self) -> Self;
+    fn sqrtf(self) -> Self;
+    fn add(self, o: Self) -> Self;
+}
+
+impl Float for f32 {
+    fn powi2(self) -> Self {
+        self.powi(2)
+    }
+
+    fn sqrtf(self) -> Self {
+        self.sqrt()
+    }
+
+    fn add(self, o: Self) -> Self {
+        self + o
+    }
+}
+
+impl Float for f64 {
+    fn powi2(self) -> Self {
+        self.powi(2)
+    }
+
+    fn sqrtf(self) -> Self {
+        self.sqrt()
+    }
+
+    fn add(self, o: Self) -> Self {
+        self + o
+    }
+}
+
+impl<T: Float + Copy> Point<T, T> {
+    fn distance_from_origin(&self) -> T {
+        (self.x.powi2().add(self.y.powi2())).sqrtf()
     }
 }
 
@@ -151,12 +260,18 @@ pub struct NewsArticle {
     pub content: String,
 }
 
-// impl Summary for NewsArticle {
-//     fn summarize(&self) -> String {
-//         format!("{}, by {} ({})", self.headline, self.author, self.location)
-//     }
-// }
-// with this commented out the default behavior for the summarize method will just print Read more... for any instance of the NewsArticle struct
+impl Summary for NewsArticle {
+    fn summarize_author(&self) -> String {
+        format!("@{}", self.author)
+    }
+
+    fn summarize(&self) -> String {
+        format!(
+            "{}, by {} ({})",
+            self.headline, self.author, self.location
+        )
+    }
+}
 
 pub struct Tweet {
     pub username: String,
@@ -194,6 +309,32 @@ pub fn notify<T: Summary>(item: T, item2: T) {
     println!("Breaking news! {}", item.summarize());
 }
 
+// notify above uses static dispatch: T is a single concrete type chosen at compile time,
+// so item and item2 must be the same struct and we get a monomorphized copy of notify per type used
+// to hold a mix of Tweet and NewsArticle in one collection we need dynamic dispatch instead,
+// which trait objects (Box<dyn Summary>) give us at the cost of a vtable lookup per call
+// trait objects require the trait to be object safe: every method must take a receiver (&self, &mut self or self)
+// and have no generic type parameters, which Summary already satisfies
+pub struct Aggregator {
+    sources: Vec<Box<dyn Summary>>,
+}
+
+impl Aggregator {
+    fn new() -> Self {
+        Self { sources: Vec::new() }
+    }
+
+    fn push(&mut self, item: Box<dyn Summary>) {
+        self.sources.push(item);
+    }
+
+    fn display_all(&self) {
+        for source in &self.sources {
+            println!("{}", source.summarize());
+        }
+    }
+}
+
 // using too many trait bounds has its downsides
 // each generic has its own trait bounds so functions with multiple generic type parameters can contain lots of trait bound information between the function's name and its parameter list
 // instead of fn some_function<T: Display + Clone, U: Clone + Debug>(t: T, u: U) -> i32 {}
@@ -244,20 +385,68 @@ impl<T: Display + PartialOrd> Pair<T> {
 
 // it's also possible to implement a trait for any type that implements another trait
 // implementations of a trait on any type that satisfies the trait bounds are called blanket implementations and are used through the standard library
-//impl<T: Display> ToString for T {
-//    / for example the standard library implements the ToString trait on any type that implements the Display trait
-//}
+// for example the standard library implements the ToString trait on any type that implements the Display trait:
+// impl<T: Display> ToString for T { ... }
+// here's a local blanket implementation that works the same way over our own Summary trait
+trait Headline {
+    fn headline(&self) -> String;
+}
+
+impl<T: Summary> Headline for T {
+    fn headline(&self) -> String {
+        format!("READ: {}", self.summarize())
+    }
+}
 
 // every reference in rust has a lifetime
 // a lifetime is the scope for which that reference is valid
 // most of the time lifetimes are implicit and inferred
 fn reference_lifetimes() {
+    // x now lives in the outer scope alongside r so the reference r holds
+    // stays valid for as long as r itself is used (x can no longer be dropped early)
+    let x = 5;
     let r;
 
     {
-        let x = 5;
         r = &x;
-    } // because r stores a reference to x and x is dropped here there is an issue
+    }
 
     println!("r: {}", r);
 }
+
+// the main aim of lifetimes is to prevent dangling references
+// the borrow checker compares scopes to determine whether all borrows are valid
+// when the lifetimes of references could be related in different ways we have to annotate them
+// using generic lifetime parameters that define the relationship between references so the borrow checker can perform its analysis
+// lifetime annotations don't change how long any of the references live, they describe the relationships
+// of the lifetimes of multiple references to each other without affecting the lifetimes
+// syntax: 'a, names start with an apostrophe and are usually all lowercase and short like generic types
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    // the signature tells rust that for some lifetime 'a the function takes two string slices
+    // that both live at least as long as 'a and it will return a string slice that also lives at least as long as 'a
+    // in practice this means the returned reference's lifetime is the smaller of the lifetimes of x and y
+    // (the borrow checker rejects any attempt to return a reference that could outlive either input)
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
+
+// a struct can hold references, but doing so requires a lifetime annotation on every reference in the struct's definition
+struct ImportantExcerpt<'a> {
+    part: &'a str,
+}
+
+impl<'a> ImportantExcerpt<'a> {
+    // this method walks through all three lifetime elision rules, which is why no lifetime is
+    // written on the output even though the method returns a reference:
+    // 1) each elided input reference gets its own lifetime parameter (&self and announcement each get one)
+    // 2) if there is exactly one input lifetime, it's assigned to all elided output lifetimes (doesn't apply here, there are two inputs)
+    // 3) if one of the inputs is &self, the lifetime of self is assigned to all elided output lifetimes (this is the rule that applies)
+    // so the compiler desugars this to fn announce_and_return_part<'b, 'c>(&'b self, announcement: &'c str) -> &'b str
+    fn announce_and_return_part(&self, announcement: &str) -> &str {
+        println!("Attention please: {}", announcement);
+        self.part
+    }
+}